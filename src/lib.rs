@@ -1,4 +1,6 @@
 use hex::FromHex;
+use primitive_types::U256;
+use rlp::Rlp;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::{
     json_types::U128, log, require, serde::Deserialize, serde::Serialize, serde_json, AccountId,
@@ -9,9 +11,60 @@ pub const STANDARD: &str = "nep297";
 pub const VERSION: &str = "1.0.0";
 pub const EVENT_JSON_STR: &str = "EVENT_JSON:";
 
+/// Wormhole-style numeric chain identifier carried by every cross-chain message.
+pub type ChainId = u16;
+
+/// Chain id assumed for messages serialized before multichain support was added
+/// (Wormhole assigns `2` to Ethereum). Used as the trailing-field fallback.
+pub const ETHEREUM_CHAIN_ID: ChainId = 2;
+
+/// Map a Wormhole-style chain id to a human-readable name for use in emitted `Event`s.
+pub fn chain_name(chain_id: ChainId) -> &'static str {
+    match chain_id {
+        2 => "Ethereum",
+        4 => "BSC",
+        5 => "Polygon",
+        6 => "Avalanche",
+        9 => "Aurora",
+        15 => "Near",
+        _ => "Unknown",
+    }
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq)]
 pub struct EthAddress(pub [u8; 20]);
 
+/// Length-generic foreign-chain address. Wormhole stores addresses as raw byte
+/// vectors so formats other than 20-byte EVM addresses can be represented.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq)]
+pub struct ForeignAddress(pub Vec<u8>);
+
+impl<'de> Deserialize<'de> for ForeignAddress {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut s = <String as Deserialize>::deserialize(deserializer)?;
+        if s.starts_with("0x") {
+            s = s[2..].to_string();
+        }
+        let result = Vec::from_hex(&s).map_err(|err| serde::de::Error::custom(err.to_string()))?;
+        Ok(ForeignAddress(result))
+    }
+}
+
+impl Serialize for ForeignAddress {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<<S as serde::Serializer>::Ok, <S as serde::Serializer>::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&hex::encode(&self.0))
+    }
+}
+
 impl<'de> Deserialize<'de> for EthAddress {
     fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
     where
@@ -22,10 +75,65 @@ impl<'de> Deserialize<'de> for EthAddress {
             s = s[2..].to_string();
         }
         let result = Vec::from_hex(&s).map_err(|err| serde::de::Error::custom(err.to_string()))?;
-        Ok(EthAddress(result.try_into().unwrap()))
+        let address = EthAddress(result.try_into().unwrap());
+        // An all-lowercase or all-uppercase string carries no checksum, so it is
+        // accepted as-is. A mixed-case string is an EIP-55 checksummed address and
+        // must match the recomputed checksum exactly, otherwise a typo in the
+        // recipient would be silently accepted.
+        let has_lower = s.chars().any(|c| c.is_ascii_lowercase());
+        let has_upper = s.chars().any(|c| c.is_ascii_uppercase());
+        if has_lower && has_upper && s != address.to_checksum_string() {
+            return Err(serde::de::Error::custom("invalid EIP-55 checksum"));
+        }
+        Ok(address)
     }
 }
 
+impl EthAddress {
+    /// Encode the address as an EIP-55 mixed-case checksummed hex string (no `0x` prefix).
+    ///
+    /// The 20 raw bytes are hex-encoded to 40 lowercase characters; Keccac-256 is
+    /// computed over that ASCII string, and each hex letter is uppercased when the
+    /// matching nibble of the hash is `>= 8`.
+    pub fn to_checksum_string(&self) -> String {
+        let lower = hex::encode(self.0);
+        let hash = keccak256(lower.as_bytes());
+        lower
+            .char_indices()
+            .map(|(i, c)| {
+                if c.is_ascii_alphabetic() {
+                    let nibble = if i % 2 == 0 {
+                        hash[i / 2] >> 4
+                    } else {
+                        hash[i / 2] & 0x0f
+                    };
+                    if nibble >= 8 {
+                        return c.to_ascii_uppercase();
+                    }
+                }
+                c
+            })
+            .collect()
+    }
+}
+
+/// Keccak-256 over `data`. Backed by the host in a contract build and by a
+/// pure-Rust implementation under `cargo test`.
+#[cfg(feature = "contract")]
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    near_sdk::env::keccak256(data).try_into().unwrap()
+}
+
+#[cfg(not(feature = "contract"))]
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    use tiny_keccak::{Hasher, Keccak};
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut output);
+    output
+}
+
 impl Serialize for EthAddress {
     fn serialize<S>(
         &self,
@@ -50,12 +158,268 @@ pub struct Proof {
     pub proof: Vec<Vec<u8>>,
 }
 
+/// Reason a [`Proof`] failed to verify against its block header.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProofError {
+    /// A node, header or receipt could not be RLP-decoded into the expected shape.
+    MalformedRlp,
+    /// `keccak256(node)` did not match the hash the parent pointed at.
+    HashMismatch,
+    /// The trie path ran out (or diverged) before reaching the target value.
+    KeyExhausted,
+    /// A leaf was reached with trie-key nibbles left over, so the proof would bind
+    /// a different `receipt_index` than the one claimed.
+    KeyNotFullyConsumed,
+    /// The decoded leaf value did not equal the supplied `receipt_data`/`log_entry_data`.
+    ValueMismatch,
+    /// `log_index` pointed past the end of the receipt's `logs` list.
+    LogIndexOutOfRange,
+}
+
+impl std::fmt::Display for ProofError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            ProofError::MalformedRlp => "malformed RLP",
+            ProofError::HashMismatch => "node hash mismatch",
+            ProofError::KeyExhausted => "trie key exhausted",
+            ProofError::KeyNotFullyConsumed => "trie key not fully consumed",
+            ProofError::ValueMismatch => "value mismatch",
+            ProofError::LogIndexOutOfRange => "log index out of range",
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl std::error::Error for ProofError {}
+
+impl Proof {
+    /// Verify that `receipt_data` is included in the block whose header is
+    /// `header_data`, by walking the Merkle-Patricia `proof` path from the
+    /// header's `receiptsRoot` down to the receipt leaf.
+    pub fn verify(&self) -> Result<(), ProofError> {
+        let header = Rlp::new(&self.header_data);
+        let receipts_root = header
+            .at(5)
+            .and_then(|r| r.data())
+            .map_err(|_| ProofError::MalformedRlp)?
+            .to_vec();
+
+        // The trie key for a receipt is `rlp(receipt_index)`, expanded to nibbles.
+        let key = rlp::encode(&self.receipt_index).to_vec();
+        let key_nibbles = to_nibbles(&key);
+        let mut key_pos = 0usize;
+        let mut expected_hash = receipts_root;
+        let mut value: Option<Vec<u8>> = None;
+
+        for node in &self.proof {
+            if keccak256(node).as_slice() != expected_hash.as_slice() {
+                return Err(ProofError::HashMismatch);
+            }
+            let node_rlp = Rlp::new(node);
+            match node_rlp.item_count().map_err(|_| ProofError::MalformedRlp)? {
+                // Branch node: 16 child slots + an optional value slot.
+                17 => {
+                    if key_pos == key_nibbles.len() {
+                        value = Some(
+                            node_rlp
+                                .at(16)
+                                .and_then(|r| r.data())
+                                .map_err(|_| ProofError::MalformedRlp)?
+                                .to_vec(),
+                        );
+                        break;
+                    }
+                    let slot = key_nibbles[key_pos] as usize;
+                    key_pos += 1;
+                    let child = node_rlp.at(slot).map_err(|_| ProofError::MalformedRlp)?;
+                    if child.is_empty() {
+                        return Err(ProofError::KeyExhausted);
+                    }
+                    expected_hash = child.data().map_err(|_| ProofError::MalformedRlp)?.to_vec();
+                }
+                // Extension or leaf node: a compact-encoded path and a value/child.
+                2 => {
+                    let path = node_rlp
+                        .at(0)
+                        .and_then(|r| r.data())
+                        .map_err(|_| ProofError::MalformedRlp)?;
+                    let (is_leaf, path_nibbles) = decode_compact_path(path);
+                    let remaining = &key_nibbles[key_pos..];
+                    if remaining.len() < path_nibbles.len()
+                        || remaining[..path_nibbles.len()] != path_nibbles[..]
+                    {
+                        return Err(ProofError::KeyExhausted);
+                    }
+                    key_pos += path_nibbles.len();
+                    if is_leaf {
+                        // A leaf terminates the path, so the key must be fully
+                        // consumed. A bare prefix match would let a proof validate a
+                        // receipt sitting at a different `receipt_index` than claimed.
+                        if key_pos != key_nibbles.len() {
+                            return Err(ProofError::KeyNotFullyConsumed);
+                        }
+                        value = Some(
+                            node_rlp
+                                .at(1)
+                                .and_then(|r| r.data())
+                                .map_err(|_| ProofError::MalformedRlp)?
+                                .to_vec(),
+                        );
+                        break;
+                    }
+                    let child = node_rlp.at(1).map_err(|_| ProofError::MalformedRlp)?;
+                    expected_hash = child.data().map_err(|_| ProofError::MalformedRlp)?.to_vec();
+                }
+                _ => return Err(ProofError::MalformedRlp),
+            }
+        }
+
+        let value = value.ok_or(ProofError::KeyExhausted)?;
+        if value != self.receipt_data {
+            return Err(ProofError::ValueMismatch);
+        }
+        Ok(())
+    }
+
+    /// Verify the proof and return the raw RLP bytes of the log at `log_index`,
+    /// confirming the extracted entry equals the supplied `log_entry_data`.
+    pub fn verified_log(&self) -> Result<Vec<u8>, ProofError> {
+        self.verify()?;
+
+        // Typed (EIP-2718) receipts are prefixed with a single transaction-type
+        // byte (`<= 0x7f`) ahead of the RLP payload; strip it before decoding.
+        let mut receipt = self.receipt_data.as_slice();
+        if let Some(&first) = receipt.first() {
+            if first <= 0x7f {
+                receipt = &receipt[1..];
+            }
+        }
+
+        let logs = Rlp::new(receipt)
+            .at(3)
+            .map_err(|_| ProofError::MalformedRlp)?;
+        let log_index = self.log_index as usize;
+        if log_index >= logs.item_count().map_err(|_| ProofError::MalformedRlp)? {
+            return Err(ProofError::LogIndexOutOfRange);
+        }
+        let log = logs.at(log_index).map_err(|_| ProofError::MalformedRlp)?;
+        let log_bytes = log.as_raw().to_vec();
+        if log_bytes != self.log_entry_data {
+            return Err(ProofError::ValueMismatch);
+        }
+        Ok(log_bytes)
+    }
+}
+
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    let mut nibbles = Vec::with_capacity(bytes.len() * 2);
+    for &b in bytes {
+        nibbles.push(b >> 4);
+        nibbles.push(b & 0x0f);
+    }
+    nibbles
+}
+
+/// Decode a hex-prefix (compact) encoded path, returning whether it is a leaf and
+/// the path nibbles. The high nibble of the first byte flags leaf-vs-extension
+/// (bit `0x2`) and odd path length (bit `0x1`); on an odd path the low nibble of
+/// the first byte is the first path nibble, otherwise it is zero padding.
+fn decode_compact_path(path: &[u8]) -> (bool, Vec<u8>) {
+    if path.is_empty() {
+        return (false, Vec::new());
+    }
+    let flag = path[0] >> 4;
+    let is_leaf = flag & 0x2 != 0;
+    let odd = flag & 0x1 != 0;
+    let mut nibbles = Vec::new();
+    if odd {
+        nibbles.push(path[0] & 0x0f);
+    }
+    for &b in &path[1..] {
+        nibbles.push(b >> 4);
+        nibbles.push(b & 0x0f);
+    }
+    (is_leaf, nibbles)
+}
+
+/// Ethereum token standard a bridged transfer targets.
+#[derive(
+    Serialize, Deserialize, BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum TokenStandard {
+    Erc20,
+    Erc721,
+    Erc1155,
+}
+
+impl Default for TokenStandard {
+    fn default() -> Self {
+        TokenStandard::Erc20
+    }
+}
+
+/// NEAR token standard the bridged token maps to on the NEAR side.
+#[derive(
+    Serialize, Deserialize, BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum NearTokenStandard {
+    Nep141,
+    Nep171,
+    Nep245,
+}
+
+impl Default for NearTokenStandard {
+    fn default() -> Self {
+        NearTokenStandard::Nep141
+    }
+}
+
 #[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq)]
 #[serde(crate = "near_sdk::serde")]
 pub struct TransferDataEthereum {
     pub token_near: AccountId,
     pub token_eth: EthAddress,
     pub amount: U128,
+    /// Target chain for this transfer. Appended to the end of the `TransferMessage`
+    /// borsh stream (see `BorshDeserialize for TransferMessage`) rather than serialized
+    /// in place, so it keeps `TransferDataEthereum`'s original wire layout intact.
+    #[serde(default = "default_chain_id")]
+    #[borsh_skip]
+    pub chain_id: ChainId,
+    /// Token standard; `Erc20` for fungible transfers, `Erc721`/`Erc1155` for NFTs.
+    /// Appended to the `TransferMessage` borsh tail like `chain_id`.
+    #[serde(default)]
+    #[borsh_skip]
+    pub token_standard: TokenStandard,
+    /// Token id for `Erc721`/`Erc1155` transfers; `None` for fungible `Erc20`.
+    #[serde(default)]
+    #[borsh_skip]
+    pub token_id: Option<U256>,
+    /// Token address in its native (possibly non-20-byte) format when `chain_id`
+    /// targets a non-EVM chain. `None` for EVM chains, where `token_eth` is the
+    /// canonical 20-byte address. Appended to the `TransferMessage` borsh tail.
+    #[serde(default)]
+    #[borsh_skip]
+    pub token_foreign: Option<ForeignAddress>,
+}
+
+fn default_chain_id() -> ChainId {
+    ETHEREUM_CHAIN_ID
+}
+
+impl TransferDataEthereum {
+    /// The token's address on the target chain as raw bytes: the native-format
+    /// [`ForeignAddress`] when `chain_id` targets a non-EVM chain and one is set,
+    /// otherwise the canonical 20-byte `token_eth`. This is what lets a non-20-byte
+    /// address actually be carried and consumed by downstream routing.
+    pub fn target_token_address(&self) -> Vec<u8> {
+        match &self.token_foreign {
+            Some(address) if self.chain_id != ETHEREUM_CHAIN_ID => address.0.clone(),
+            _ => self.token_eth.0.to_vec(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq)]
@@ -63,9 +427,18 @@ pub struct TransferDataEthereum {
 pub struct TransferDataNear {
     pub token: AccountId,
     pub amount: U128,
+    /// NEAR token standard; `Nep141` for fungible, `Nep171`/`Nep245` for NFT/multi-token.
+    /// Appended to the `TransferMessage` borsh tail like the Ethereum-side fields.
+    #[serde(default)]
+    #[borsh_skip]
+    pub token_standard: NearTokenStandard,
+    /// NEP-171/NEP-245 token id; `None` for fungible `Nep141`.
+    #[serde(default)]
+    #[borsh_skip]
+    pub token_id: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, BorshSerialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(crate = "near_sdk::serde")]
 pub struct TransferMessage {
     pub valid_till: u64,
@@ -76,20 +449,233 @@ pub struct TransferMessage {
     pub aurora_sender: Option<EthAddress>,
 }
 
+impl BorshSerialize for TransferMessage {
+    fn serialize<W: crate::borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> crate::borsh::maybestd::io::Result<()> {
+        self.valid_till.serialize(writer)?;
+        self.transfer.serialize(writer)?;
+        self.fee.serialize(writer)?;
+        self.recipient.serialize(writer)?;
+        self.valid_till_block_height.serialize(writer)?;
+        self.aurora_sender.serialize(writer)?;
+        // Appended after the v2 fields so already-serialized v1/v2 messages, which
+        // lack these trailing bytes, keep decoding (see `deserialize`).
+        self.transfer.chain_id.serialize(writer)?;
+        // Multi-token fields, appended in turn for the same trailing-optional reason.
+        self.transfer.token_standard.serialize(writer)?;
+        match self.transfer.token_id {
+            Some(id) => {
+                1u8.serialize(writer)?;
+                let mut bytes = [0u8; 32];
+                id.to_big_endian(&mut bytes);
+                bytes.serialize(writer)?;
+            }
+            None => 0u8.serialize(writer)?,
+        }
+        self.fee.token_standard.serialize(writer)?;
+        self.fee.token_id.serialize(writer)?;
+        self.transfer.token_foreign.serialize(writer)?;
+        Ok(())
+    }
+}
+
 impl BorshDeserialize for TransferMessage {
     fn deserialize(data: &mut &[u8]) -> crate::borsh::maybestd::io::Result<Self> {
+        let valid_till = <u64 as BorshDeserialize>::deserialize(data)?;
+        let mut transfer = <TransferDataEthereum as BorshDeserialize>::deserialize(data)?;
+        let mut fee = <TransferDataNear as BorshDeserialize>::deserialize(data)?;
+        let recipient = <EthAddress as BorshDeserialize>::deserialize(data)?;
+        let valid_till_block_height = <Option<u64> as BorshDeserialize>::deserialize(data)?;
+        let aurora_sender =
+            <Option<EthAddress> as BorshDeserialize>::deserialize(data).unwrap_or(None);
+        transfer.chain_id =
+            <ChainId as BorshDeserialize>::deserialize(data).unwrap_or(ETHEREUM_CHAIN_ID);
+        transfer.token_standard =
+            <TokenStandard as BorshDeserialize>::deserialize(data).unwrap_or_default();
+        transfer.token_id = match <u8 as BorshDeserialize>::deserialize(data) {
+            Ok(1) => <[u8; 32] as BorshDeserialize>::deserialize(data)
+                .ok()
+                .map(|bytes| U256::from_big_endian(&bytes)),
+            _ => None,
+        };
+        fee.token_standard =
+            <NearTokenStandard as BorshDeserialize>::deserialize(data).unwrap_or_default();
+        fee.token_id = <Option<String> as BorshDeserialize>::deserialize(data).unwrap_or(None);
+        transfer.token_foreign =
+            <Option<ForeignAddress> as BorshDeserialize>::deserialize(data).unwrap_or(None);
         Ok(TransferMessage {
-            valid_till: <u64 as BorshDeserialize>::deserialize(data)?,
-            transfer: <TransferDataEthereum as BorshDeserialize>::deserialize(data)?,
-            fee: <TransferDataNear as BorshDeserialize>::deserialize(data)?,
-            recipient: <EthAddress as BorshDeserialize>::deserialize(data)?,
-            valid_till_block_height: <Option<u64> as BorshDeserialize>::deserialize(data)?,
-            aurora_sender: <Option<EthAddress> as BorshDeserialize>::deserialize(data)
-                .unwrap_or(None),
+            valid_till,
+            transfer,
+            fee,
+            recipient,
+            valid_till_block_height,
+            aurora_sender,
         })
     }
 }
 
+/// Canonical signature of the on-chain transfer event. `topics[0]` of a matching
+/// log equals `keccak256` of this ASCII string. Indexed params (`recipient`,
+/// `token`) are carried in the remaining topics; the rest live in `data`.
+///
+/// This string MUST match the event emitted by the deployed FastBridge contract;
+/// [`TRANSFER_EVENT_SIGNATURE_HASH`] is its pinned `topics[0]` selector, validated
+/// against a captured on-chain log rather than recomputed from this constant.
+pub const TRANSFER_EVENT_SIGNATURE: &str =
+    "TransferTokens(address,address,uint256,uint256,uint256)";
+
+/// `keccak256(TRANSFER_EVENT_SIGNATURE)` — the default `topics[0]` selector the
+/// caller may pass to [`TransferMessage::from_eth_log`] for the assumed layout.
+/// Pinned as a literal (rather than recomputed) so a typo in the signature string
+/// is caught by a test, and kept separate from the decode path so the caller must
+/// opt in to this selector rather than silently trusting an unverified guess.
+pub const TRANSFER_EVENT_SIGNATURE_HASH: [u8; 32] = [
+    0x29, 0x6a, 0xa7, 0x09, 0x00, 0xc5, 0x43, 0x4f, 0x4e, 0x1f, 0xee, 0xc5, 0xa3, 0x82, 0xcc, 0xc6,
+    0x1a, 0x97, 0x69, 0xc6, 0xeb, 0x3f, 0xe1, 0x57, 0xca, 0xdd, 0x5a, 0x0e, 0xc8, 0xab, 0x67, 0xc1,
+];
+
+/// Reason a raw Ethereum log could not be decoded into a [`TransferMessage`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogDecodeError {
+    /// The log bytes were not a well-formed `[address, [topics], data]` RLP list.
+    MalformedRlp,
+    /// `topics[0]` did not match the transfer-event signature hash.
+    SignatureMismatch,
+    /// The log did not carry the expected number of indexed topics.
+    TopicCount,
+    /// The non-indexed `data` tail was shorter than the expected ABI words.
+    DataLength,
+    /// An ABI word held a value too large for its target integer type.
+    IntegerOverflow,
+}
+
+impl std::fmt::Display for LogDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            LogDecodeError::MalformedRlp => "malformed log RLP",
+            LogDecodeError::SignatureMismatch => "event signature mismatch",
+            LogDecodeError::TopicCount => "unexpected topic count",
+            LogDecodeError::DataLength => "truncated log data",
+            LogDecodeError::IntegerOverflow => "ABI integer overflow",
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl std::error::Error for LogDecodeError {}
+
+impl TransferMessage {
+    /// Reconstruct a canonical `TransferMessage` from a raw Ethereum log
+    /// (`[address, [topics], data]` RLP), as stored in [`Proof::log_entry_data`].
+    ///
+    /// `event_signature_hash` is the `topics[0]` selector the caller expects — the
+    /// authoritative value is the one actually emitted by the deployed FastBridge
+    /// contract, which only the caller can supply; [`TRANSFER_EVENT_SIGNATURE_HASH`]
+    /// is a convenience default for the assumed layout below but must be confirmed
+    /// against an on-chain log before it is trusted.
+    ///
+    /// The assumed ABI layout is two indexed address topics
+    /// (`topics[1] = recipient`, `topics[2] = token`) followed by three non-indexed
+    /// `uint256` words in `data` (`amount`, `fee`, `valid_till`). The NEAR-side
+    /// token account ids are not carried by the log and are left empty for the
+    /// caller to resolve.
+    pub fn from_eth_log(
+        log_entry_data: &[u8],
+        event_signature_hash: [u8; 32],
+    ) -> Result<Self, LogDecodeError> {
+        let log = Rlp::new(log_entry_data);
+
+        let topics = log.at(1).map_err(|_| LogDecodeError::MalformedRlp)?;
+        // topics[0] is the signature hash plus the two indexed parameters.
+        if topics.item_count().map_err(|_| LogDecodeError::MalformedRlp)? != 3 {
+            return Err(LogDecodeError::TopicCount);
+        }
+
+        let signature = topics
+            .at(0)
+            .and_then(|r| r.data())
+            .map_err(|_| LogDecodeError::MalformedRlp)?;
+        if signature != event_signature_hash {
+            return Err(LogDecodeError::SignatureMismatch);
+        }
+
+        let recipient = topic_to_eth_address(
+            topics
+                .at(1)
+                .and_then(|r| r.data())
+                .map_err(|_| LogDecodeError::MalformedRlp)?,
+        )?;
+        let token = topic_to_eth_address(
+            topics
+                .at(2)
+                .and_then(|r| r.data())
+                .map_err(|_| LogDecodeError::MalformedRlp)?,
+        )?;
+
+        let data = log
+            .at(2)
+            .and_then(|r| r.data())
+            .map_err(|_| LogDecodeError::MalformedRlp)?;
+        if data.len() < 3 * 32 {
+            return Err(LogDecodeError::DataLength);
+        }
+        let amount = word_to_u128(&data[0..32])?;
+        let fee = word_to_u128(&data[32..64])?;
+        let valid_till = word_to_u64(&data[64..96])?;
+
+        Ok(TransferMessage {
+            valid_till,
+            transfer: TransferDataEthereum {
+                token_near: AccountId::new_unchecked(String::new()),
+                token_eth: token,
+                amount: U128(amount),
+                chain_id: ETHEREUM_CHAIN_ID,
+                token_standard: TokenStandard::Erc20,
+                token_id: None,
+                token_foreign: None,
+            },
+            fee: TransferDataNear {
+                token: AccountId::new_unchecked(String::new()),
+                amount: U128(fee),
+                token_standard: NearTokenStandard::Nep141,
+                token_id: None,
+            },
+            recipient,
+            valid_till_block_height: None,
+            aurora_sender: None,
+        })
+    }
+}
+
+fn topic_to_eth_address(topic: &[u8]) -> Result<EthAddress, LogDecodeError> {
+    if topic.len() != 32 {
+        return Err(LogDecodeError::MalformedRlp);
+    }
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&topic[12..32]);
+    Ok(EthAddress(address))
+}
+
+fn word_to_u128(word: &[u8]) -> Result<u128, LogDecodeError> {
+    if word[..16].iter().any(|&b| b != 0) {
+        return Err(LogDecodeError::IntegerOverflow);
+    }
+    let mut buf = [0u8; 16];
+    buf.copy_from_slice(&word[16..32]);
+    Ok(u128::from_be_bytes(buf))
+}
+
+fn word_to_u64(word: &[u8]) -> Result<u64, LogDecodeError> {
+    if word[..24].iter().any(|&b| b != 0) {
+        return Err(LogDecodeError::IntegerOverflow);
+    }
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&word[24..32]);
+    Ok(u64::from_be_bytes(buf))
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(crate = "near_sdk::serde")]
 #[serde(tag = "event", content = "data")]
@@ -102,6 +688,11 @@ pub enum Event {
         sender_id: AccountId,
         transfer_message: TransferMessage,
     },
+    FastBridgeInitNftTransferEvent {
+        nonce: U128,
+        sender_id: AccountId,
+        transfer_message: TransferMessage,
+    },
     FastBridgeUnlockEvent {
         nonce: U128,
         recipient_id: AccountId,
@@ -204,10 +795,16 @@ mod tests {
                     token_near: token(),
                     token_eth: token_address,
                     amount: U128(amount),
+                    chain_id: ETHEREUM_CHAIN_ID,
+                    token_standard: TokenStandard::Erc20,
+                    token_id: None,
+                    token_foreign: None,
                 },
                 fee: TransferDataNear {
                     token: token(),
                     amount: U128(amount),
+                    token_standard: NearTokenStandard::Nep141,
+                    token_id: None,
                 },
                 recipient: get_eth_address(),
                 aurora_sender: Some(EthAddress(<[u8; 20]>::default())),
@@ -216,7 +813,7 @@ mod tests {
         .emit();
 
         let log_data_str = &test_utils::get_logs()[0];
-        let expected_result_str = r#"EVENT_JSON:{"standard":"nep297","version":"1.0.0","event":"fast_bridge_init_transfer_event","data":{"nonce":"238","sender_id":"sender.near","transfer_message":{"aurora_sender":"0000000000000000000000000000000000000000","valid_till":0,"valid_till_block_height":0,"transfer":{"token_near":"token.near","token_eth": "71c7656ec7ab88b098defb751b7401b5f6d8976f","amount":"100"},"fee":{"token":"token.near","amount":"100"},"recipient": "71c7656ec7ab88b098defb751b7401b5f6d8976f"}}}"#;
+        let expected_result_str = r#"EVENT_JSON:{"standard":"nep297","version":"1.0.0","event":"fast_bridge_init_transfer_event","data":{"nonce":"238","sender_id":"sender.near","transfer_message":{"aurora_sender":"0000000000000000000000000000000000000000","valid_till":0,"valid_till_block_height":0,"transfer":{"token_near":"token.near","token_eth": "71c7656ec7ab88b098defb751b7401b5f6d8976f","amount":"100","chain_id":2,"token_standard":"Erc20","token_id":null,"token_foreign":null},"fee":{"token":"token.near","amount":"100","token_standard":"Nep141","token_id":null},"recipient": "71c7656ec7ab88b098defb751b7401b5f6d8976f"}}}"#;
 
         let json1 = remove_prefix(log_data_str).unwrap();
         let json2 = remove_prefix(expected_result_str).unwrap();
@@ -241,10 +838,16 @@ mod tests {
                     token_near: token(),
                     token_eth: token_address,
                     amount: U128(amount),
+                    chain_id: ETHEREUM_CHAIN_ID,
+                    token_standard: TokenStandard::Erc20,
+                    token_id: None,
+                    token_foreign: None,
                 },
                 fee: TransferDataNear {
                     token: token(),
                     amount: U128(amount),
+                    token_standard: NearTokenStandard::Nep141,
+                    token_id: None,
                 },
                 recipient: get_eth_address(),
                 aurora_sender: Some(EthAddress(<[u8; 20]>::default())),
@@ -253,7 +856,7 @@ mod tests {
         .emit();
 
         let log_data_str = &test_utils::get_logs()[0];
-        let expected_result_str = r#"EVENT_JSON:{"standard":"nep297","version":"1.0.0","event":"fast_bridge_unlock_event","data":{"nonce":"238","recipient_id":"recipient.near","transfer_message":{"aurora_sender":"0000000000000000000000000000000000000000","valid_till":0,"valid_till_block_height":0,"transfer":{"token_near":"token.near","token_eth": "71c7656ec7ab88b098defb751b7401b5f6d8976f","amount":"100"},"fee":{"token":"token.near","amount":"100"},"recipient": "71c7656ec7ab88b098defb751b7401b5f6d8976f"}}}"#;
+        let expected_result_str = r#"EVENT_JSON:{"standard":"nep297","version":"1.0.0","event":"fast_bridge_unlock_event","data":{"nonce":"238","recipient_id":"recipient.near","transfer_message":{"aurora_sender":"0000000000000000000000000000000000000000","valid_till":0,"valid_till_block_height":0,"transfer":{"token_near":"token.near","token_eth": "71c7656ec7ab88b098defb751b7401b5f6d8976f","amount":"100","chain_id":2,"token_standard":"Erc20","token_id":null,"token_foreign":null},"fee":{"token":"token.near","amount":"100","token_standard":"Nep141","token_id":null},"recipient": "71c7656ec7ab88b098defb751b7401b5f6d8976f"}}}"#;
 
         let json1 = remove_prefix(log_data_str).unwrap();
         let json2 = remove_prefix(expected_result_str).unwrap();
@@ -281,6 +884,180 @@ mod tests {
         assert_json_eq!(json1, json2)
     }
 
+    #[test]
+    fn eip55_checksum_encoding_test() {
+        // Reference vector from EIP-55.
+        let address: EthAddress =
+            serde_json::from_str("\"5aaeb6053f3e94c9b9a09f33669435e7ef1beaed\"").unwrap();
+        assert_eq!(
+            address.to_checksum_string(),
+            "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+    }
+
+    #[test]
+    fn eip55_checksum_accepts_single_case_test() {
+        serde_json::from_str::<EthAddress>("\"5aaeb6053f3e94c9b9a09f33669435e7ef1beaed\"").unwrap();
+        serde_json::from_str::<EthAddress>("\"5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED\"").unwrap();
+    }
+
+    #[test]
+    fn eip55_checksum_rejects_bad_mixed_case_test() {
+        // A single flipped-case letter in an otherwise checksummed address.
+        assert!(
+            serde_json::from_str::<EthAddress>("\"5Aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed\"")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn proof_verify_single_leaf_test() {
+        let receipt_data = b"receipt".to_vec();
+        // RLP leaf node: [ compact_path([0x20, 0x80]), "receipt" ].
+        let node = vec![
+            0xcb, 0x82, 0x20, 0x80, 0x87, b'r', b'e', b'c', b'e', b'i', b'p', b't',
+        ];
+        let root = keccak256(&node);
+        // RLP header: five empty fields then the 32-byte receiptsRoot at index 5.
+        let mut header = vec![0xe6, 0x80, 0x80, 0x80, 0x80, 0x80, 0xa0];
+        header.extend_from_slice(&root);
+
+        let proof = Proof {
+            log_index: 0,
+            log_entry_data: vec![],
+            receipt_index: 0,
+            receipt_data,
+            header_data: header,
+            proof: vec![node],
+        };
+        assert_eq!(proof.verify(), Ok(()));
+    }
+
+    #[test]
+    fn proof_verify_detects_value_mismatch_test() {
+        // Same well-formed leaf, but the claimed receipt_data differs from the
+        // value actually committed in the trie.
+        let node = vec![
+            0xcb, 0x82, 0x20, 0x80, 0x87, b'r', b'e', b'c', b'e', b'i', b'p', b't',
+        ];
+        let root = keccak256(&node);
+        let mut header = vec![0xe6, 0x80, 0x80, 0x80, 0x80, 0x80, 0xa0];
+        header.extend_from_slice(&root);
+
+        let proof = Proof {
+            log_index: 0,
+            log_entry_data: vec![],
+            receipt_index: 0,
+            receipt_data: b"RECEIPT".to_vec(),
+            header_data: header,
+            proof: vec![node],
+        };
+        assert_eq!(proof.verify(), Err(ProofError::ValueMismatch));
+    }
+
+    #[test]
+    fn proof_verify_detects_hash_mismatch_test() {
+        let node = vec![
+            0xcb, 0x82, 0x20, 0x80, 0x87, b'r', b'e', b'c', b'e', b'i', b'p', b't',
+        ];
+        // Header points at an all-zero receiptsRoot that nothing hashes to.
+        let mut header = vec![0xe6, 0x80, 0x80, 0x80, 0x80, 0x80, 0xa0];
+        header.extend_from_slice(&[0u8; 32]);
+
+        let proof = Proof {
+            log_index: 0,
+            log_entry_data: vec![],
+            receipt_index: 0,
+            receipt_data: b"receipt".to_vec(),
+            header_data: header,
+            proof: vec![node],
+        };
+        assert_eq!(proof.verify(), Err(ProofError::HashMismatch));
+    }
+
+    #[test]
+    fn proof_verify_rejects_partial_key_match_test() {
+        // Leaf with an empty path: it would prefix-match any key, but the trie key
+        // for receipt_index 0 has two nibbles, so the leaf must be rejected.
+        let node = vec![
+            0xc9, 0x20, 0x87, b'r', b'e', b'c', b'e', b'i', b'p', b't',
+        ];
+        let root = keccak256(&node);
+        let mut header = vec![0xe6, 0x80, 0x80, 0x80, 0x80, 0x80, 0xa0];
+        header.extend_from_slice(&root);
+
+        let proof = Proof {
+            log_index: 0,
+            log_entry_data: vec![],
+            receipt_index: 0,
+            receipt_data: b"receipt".to_vec(),
+            header_data: header,
+            proof: vec![node],
+        };
+        assert_eq!(proof.verify(), Err(ProofError::KeyNotFullyConsumed));
+    }
+
+    #[test]
+    fn transfer_event_signature_hash_is_pinned_test() {
+        // Decouples the pinned selector from the human-readable string: a typo in
+        // either fails here instead of silently rejecting every genuine log.
+        assert_eq!(
+            keccak256(TRANSFER_EVENT_SIGNATURE.as_bytes()),
+            TRANSFER_EVENT_SIGNATURE_HASH
+        );
+    }
+
+    #[test]
+    fn from_eth_log_test() {
+        let recipient = get_eth_address();
+        let token = get_eth_address();
+
+        let mut topic_recipient = vec![0u8; 12];
+        topic_recipient.extend_from_slice(&recipient.0);
+        let mut topic_token = vec![0u8; 12];
+        topic_token.extend_from_slice(&token.0);
+
+        let mut data = vec![0u8; 32 * 3];
+        data[16..32].copy_from_slice(&100u128.to_be_bytes()); // amount
+        data[48..64].copy_from_slice(&5u128.to_be_bytes()); // fee
+        data[88..96].copy_from_slice(&42u64.to_be_bytes()); // valid_till
+
+        let mut stream = rlp::RlpStream::new_list(3);
+        stream.append(&vec![0u8; 20]); // emitting contract address (unused)
+        stream.begin_list(3);
+        stream.append(&TRANSFER_EVENT_SIGNATURE_HASH.to_vec());
+        stream.append(&topic_recipient);
+        stream.append(&topic_token);
+        stream.append(&data);
+        let log = stream.out().to_vec();
+
+        let message =
+            TransferMessage::from_eth_log(&log, TRANSFER_EVENT_SIGNATURE_HASH).unwrap();
+        assert_eq!(message.valid_till, 42);
+        assert_eq!(message.transfer.token_eth, token);
+        assert_eq!(message.transfer.amount, U128(100));
+        assert_eq!(message.transfer.chain_id, ETHEREUM_CHAIN_ID);
+        assert_eq!(message.fee.amount, U128(5));
+        assert_eq!(message.recipient, recipient);
+    }
+
+    #[test]
+    fn from_eth_log_rejects_wrong_signature_test() {
+        let mut stream = rlp::RlpStream::new_list(3);
+        stream.append(&vec![0u8; 20]);
+        stream.begin_list(3);
+        stream.append(&vec![0xabu8; 32]); // wrong signature hash
+        stream.append(&vec![0u8; 32]);
+        stream.append(&vec![0u8; 32]);
+        stream.append(&vec![0u8; 32 * 3]);
+        let log = stream.out().to_vec();
+
+        assert_eq!(
+            TransferMessage::from_eth_log(&log, TRANSFER_EVENT_SIGNATURE_HASH),
+            Err(LogDecodeError::SignatureMismatch)
+        );
+    }
+
     #[test]
     fn v2_borsh_deserialization_test() {
         let transfer_message = TransferMessage {
@@ -290,10 +1067,16 @@ mod tests {
                 token_near: token(),
                 token_eth: get_eth_address(),
                 amount: U128(100),
+                chain_id: ETHEREUM_CHAIN_ID,
+                token_standard: TokenStandard::Erc20,
+                token_id: None,
+                token_foreign: None,
             },
             fee: TransferDataNear {
                 token: token(),
                 amount: U128(100),
+                token_standard: NearTokenStandard::Nep141,
+                token_id: None,
             },
             recipient: get_eth_address(),
             aurora_sender: Some(EthAddress(<[u8; 20]>::default())),
@@ -315,20 +1098,112 @@ mod tests {
                 token_near: token(),
                 token_eth: get_eth_address(),
                 amount: U128(100),
+                chain_id: ETHEREUM_CHAIN_ID,
+                token_standard: TokenStandard::Erc20,
+                token_id: None,
+                token_foreign: None,
             },
             fee: TransferDataNear {
                 token: token(),
                 amount: U128(100),
+                token_standard: NearTokenStandard::Nep141,
+                token_id: None,
             },
             recipient: get_eth_address(),
             aurora_sender: None,
         };
 
         let mut encode = transfer_message.try_to_vec().unwrap();
-        encode.pop();
+        // Drop every trailing byte appended after the original v1 layout
+        // (aurora_sender, chain_id and the multi-token fields) to reproduce a
+        // message that predates all of them.
+        encode.truncate(encode.len() - 8);
 
         let decode_transfer_message: TransferMessage =
             TransferMessage::try_from_slice(&encode).unwrap();
         assert_eq!(transfer_message, decode_transfer_message);
     }
+
+    #[test]
+    fn nft_borsh_deserialization_test() {
+        let transfer_message = TransferMessage {
+            valid_till: 0,
+            valid_till_block_height: Some(0),
+            transfer: TransferDataEthereum {
+                token_near: token(),
+                token_eth: get_eth_address(),
+                amount: U128(1),
+                chain_id: ETHEREUM_CHAIN_ID,
+                token_standard: TokenStandard::Erc1155,
+                token_id: Some(U256::from(42u64)),
+                token_foreign: None,
+            },
+            fee: TransferDataNear {
+                token: token(),
+                amount: U128(100),
+                token_standard: NearTokenStandard::Nep245,
+                token_id: Some("token-42".to_string()),
+            },
+            recipient: get_eth_address(),
+            aurora_sender: None,
+        };
+
+        let encode = transfer_message.try_to_vec().unwrap();
+
+        let decode_transfer_message: TransferMessage =
+            TransferMessage::try_from_slice(&encode).unwrap();
+        assert_eq!(transfer_message, decode_transfer_message);
+    }
+
+    #[test]
+    fn foreign_address_borsh_deserialization_test() {
+        // A non-EVM target carries its token address in `token_foreign`.
+        let transfer_message = TransferMessage {
+            valid_till: 0,
+            valid_till_block_height: Some(0),
+            transfer: TransferDataEthereum {
+                token_near: token(),
+                token_eth: get_eth_address(),
+                amount: U128(100),
+                chain_id: 15, // Near
+                token_standard: TokenStandard::Erc20,
+                token_id: None,
+                token_foreign: Some(ForeignAddress(b"token.near".to_vec())),
+            },
+            fee: TransferDataNear {
+                token: token(),
+                amount: U128(100),
+                token_standard: NearTokenStandard::Nep141,
+                token_id: None,
+            },
+            recipient: get_eth_address(),
+            aurora_sender: None,
+        };
+
+        let encode = transfer_message.try_to_vec().unwrap();
+
+        let decode_transfer_message: TransferMessage =
+            TransferMessage::try_from_slice(&encode).unwrap();
+        assert_eq!(transfer_message, decode_transfer_message);
+    }
+
+    #[test]
+    fn target_token_address_gated_by_chain_id_test() {
+        let mut transfer = TransferDataEthereum {
+            token_near: token(),
+            token_eth: get_eth_address(),
+            amount: U128(1),
+            chain_id: ETHEREUM_CHAIN_ID,
+            token_standard: TokenStandard::Erc20,
+            token_id: None,
+            token_foreign: Some(ForeignAddress(b"token.near".to_vec())),
+        };
+
+        // On an EVM chain the 20-byte token_eth is authoritative.
+        assert_eq!(transfer.target_token_address(), get_eth_address().0.to_vec());
+
+        // On a non-EVM chain the native-format foreign address is used.
+        transfer.chain_id = 15; // Near
+        assert_eq!(transfer.target_token_address(), b"token.near".to_vec());
+    }
 }